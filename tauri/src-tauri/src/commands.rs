@@ -1,10 +1,23 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
 use tauri::menu::MenuItemKind;
-use tauri::{AppHandle, Manager};
+use tauri::webview::PageLoadEvent;
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
 use tauri_plugin_dialog::DialogExt;
 
+/// Counter used to give each new editor window a unique label.
+static NEXT_WINDOW_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Counter mixed into atomic-write temp file names so two windows saving
+/// the same path concurrently never race on the same temp file.
+static NEXT_TMP_FILE_ID: AtomicUsize = AtomicUsize::new(1);
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FileContent {
     pub path: String,
@@ -16,6 +29,10 @@ pub struct FileResult {
     pub success: bool,
     pub content: Option<String>,
     pub error: Option<String>,
+    /// Unix timestamp (seconds) of the file's modification time after a
+    /// successful read/write, so the frontend can later detect external
+    /// changes via `check_file_changed`.
+    pub mtime: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -34,38 +51,98 @@ pub fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
+/// Modification time of `path` as a Unix timestamp in seconds.
+fn file_mtime(path: &Path) -> std::io::Result<u64> {
+    let modified = fs::metadata(path)?.modified()?;
+    Ok(modified.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs())
+}
+
 #[tauri::command]
-pub fn read_file(path: &str) -> FileResult {
+pub fn read_file(app: AppHandle, path: &str) -> FileResult {
     match fs::read_to_string(path) {
-        Ok(content) => FileResult {
-            success: true,
-            content: Some(content),
-            error: None,
-        },
+        Ok(content) => {
+            crate::recent_files::record(&app, path.to_string());
+            FileResult {
+                success: true,
+                content: Some(content),
+                error: None,
+                mtime: file_mtime(Path::new(path)).ok(),
+            }
+        }
         Err(e) => FileResult {
             success: false,
             content: None,
             error: Some(e.to_string()),
+            mtime: None,
         },
     }
 }
 
+/// Write `content` atomically: write to a sibling temp file in the same
+/// directory, fsync it, then rename over `path`. The rename is atomic on
+/// the same filesystem, so a crash mid-write can never truncate or
+/// corrupt the existing document.
+fn atomic_write(path: &str, content: &str) -> Result<u64, String> {
+    let target = Path::new(path);
+    let dir = match target.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let file_name = target
+        .file_name()
+        .ok_or_else(|| "path has no file name".to_string())?;
+    let tmp_id = NEXT_TMP_FILE_ID.fetch_add(1, Ordering::Relaxed);
+    let tmp_path = dir.join(format!(
+        ".{}.{}-{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        tmp_id
+    ));
+
+    let write_result = (|| -> std::io::Result<()> {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()
+    })();
+
+    if let Err(e) = write_result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e.to_string());
+    }
+
+    fs::rename(&tmp_path, target).map_err(|e| e.to_string())?;
+    file_mtime(target).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn write_file(path: &str, content: &str) -> FileResult {
-    match fs::write(path, content) {
-        Ok(_) => FileResult {
+    match atomic_write(path, content) {
+        Ok(mtime) => FileResult {
             success: true,
             content: None,
             error: None,
+            mtime: Some(mtime),
         },
         Err(e) => FileResult {
             success: false,
             content: None,
-            error: Some(e.to_string()),
+            error: Some(e),
+            mtime: None,
         },
     }
 }
 
+/// Check whether `path` was modified on disk since `known_mtime`, so the
+/// frontend can warn before silently overwriting an externally-changed
+/// file. A failed lookup (e.g. the file was deleted) also counts as
+/// changed, since that's the most destructive case to silently miss.
+#[tauri::command]
+pub fn check_file_changed(path: &str, known_mtime: u64) -> bool {
+    file_mtime(Path::new(path))
+        .map(|mtime| mtime != known_mtime)
+        .unwrap_or(true)
+}
+
 /// Create a new file - clears current content and returns success
 #[tauri::command]
 pub fn new_file() -> NewFileResult {
@@ -91,10 +168,13 @@ pub async fn open_file_dialog(app: AppHandle) -> Result<DialogResult, String> {
     let file_path = rx.recv().map_err(|e| e.to_string())?;
 
     match file_path {
-        Some(path) => Ok(DialogResult {
-            path: Some(path.to_string()),
-            canceled: false,
-        }),
+        Some(path) => {
+            crate::recent_files::record(&app, path.to_string());
+            Ok(DialogResult {
+                path: Some(path.to_string()),
+                canceled: false,
+            })
+        }
         None => Ok(DialogResult {
             path: None,
             canceled: true,
@@ -130,10 +210,13 @@ pub async fn save_file_dialog(
     let file_path = rx.recv().map_err(|e| e.to_string())?;
 
     match file_path {
-        Some(path) => Ok(DialogResult {
-            path: Some(path.to_string()),
-            canceled: false,
-        }),
+        Some(path) => {
+            crate::recent_files::record(&app, path.to_string());
+            Ok(DialogResult {
+                path: Some(path.to_string()),
+                canceled: false,
+            })
+        }
         None => Ok(DialogResult {
             path: None,
             canceled: true,
@@ -141,6 +224,37 @@ pub async fn save_file_dialog(
     }
 }
 
+/// Open a file in a brand-new editor window, turning Medit into a
+/// multi-document editor. The new window loads the same frontend; if
+/// `path` is given it is forwarded once the page has actually finished
+/// loading via the existing `menu:file:open-recent` event so the
+/// frontend loads it.
+#[tauri::command]
+pub async fn open_in_new_window(app: AppHandle, path: Option<String>) -> Result<(), String> {
+    let id = NEXT_WINDOW_ID.fetch_add(1, Ordering::Relaxed);
+    let label = format!("editor-{id}");
+
+    let pending_path = Mutex::new(path);
+
+    WebviewWindowBuilder::new(&app, &label, WebviewUrl::App("index.html".into()))
+        .title("Medit")
+        .on_page_load(move |window, payload| {
+            // `on_page_load` fires for every navigation (Started, then
+            // Finished); only forward the file once the page is actually
+            // ready, rather than racing a frontend-emitted readiness event
+            // that nothing in this app emits.
+            if payload.event() == PageLoadEvent::Finished {
+                if let Some(path) = pending_path.lock().unwrap().take() {
+                    let _ = window.emit("menu:file:open-recent", path);
+                }
+            }
+        })
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
 /// Exit the application safely
 #[tauri::command]
 pub fn exit_app(app: AppHandle) -> Result<(), String> {
@@ -148,6 +262,21 @@ pub fn exit_app(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Mirror a view-mode switch initiated by the frontend back onto the
+/// View menu's radio group of `CheckMenuItem`s. The menu is shared
+/// app-wide (see [`crate::menu::set_active_view_mode`]), so this is an
+/// application-wide setting, not scoped to the calling window/document.
+#[tauri::command]
+pub fn set_active_view_mode(app: AppHandle, mode: String) -> Result<(), String> {
+    let mode_id = match mode.as_str() {
+        "edit" => "view:edit-mode",
+        "preview" => "view:preview-mode",
+        "split" => "view:split-mode",
+        other => return Err(format!("unknown view mode: {other}")),
+    };
+    crate::menu::set_active_view_mode(&app, mode_id).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn update_menu_labels(app: AppHandle, labels: HashMap<String, String>) -> Result<(), String> {
     let Some(menu) = app.menu() else {
@@ -184,3 +313,79 @@ pub fn update_menu_labels(app: AppHandle, labels: HashMap<String, String>) -> Re
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "medit-test-{}-{}",
+            std::process::id(),
+            NEXT_TMP_FILE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn atomic_write_creates_file_with_given_content() {
+        let dir = temp_dir();
+        let path = dir.join("note.md");
+
+        atomic_write(path.to_str().unwrap(), "hello").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello");
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn atomic_write_overwrites_existing_content_without_leaving_temp_files() {
+        let dir = temp_dir();
+        let path = dir.join("note.md");
+        fs::write(&path, "old").unwrap();
+
+        atomic_write(path.to_str().unwrap(), "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        let leftover_tmp_files = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path() != path)
+            .count();
+        assert_eq!(leftover_tmp_files, 0);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn atomic_write_returns_the_files_new_mtime() {
+        let dir = temp_dir();
+        let path = dir.join("note.md");
+
+        let mtime = atomic_write(path.to_str().unwrap(), "hello").unwrap();
+
+        assert_eq!(mtime, file_mtime(&path).unwrap());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn file_mtime_errors_for_a_missing_file() {
+        let dir = temp_dir();
+        let missing = dir.join("does-not-exist.md");
+
+        assert!(file_mtime(&missing).is_err());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn check_file_changed_is_true_when_the_file_was_deleted() {
+        let dir = temp_dir();
+        let path = dir.join("note.md");
+        fs::write(&path, "hello").unwrap();
+        let known_mtime = file_mtime(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(check_file_changed(path.to_str().unwrap(), known_mtime));
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,13 +1,35 @@
 use tauri::generate_context;
 use tauri::{Emitter, Manager};
 
+mod commands;
 mod menu;
+mod recent_files;
+
+use recent_files::RecentFilesState;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_fs::init())
+        .invoke_handler(tauri::generate_handler![
+            commands::greet,
+            commands::read_file,
+            commands::write_file,
+            commands::new_file,
+            commands::open_file_dialog,
+            commands::save_file_dialog,
+            commands::open_in_new_window,
+            commands::exit_app,
+            commands::set_active_view_mode,
+            commands::check_file_changed,
+            commands::update_menu_labels,
+        ])
         .setup(|app| {
+            // Load the persisted recent-files list before the menu is built,
+            // so "Open Recent" starts populated.
+            let recent = recent_files::load(app.handle());
+            app.manage(RecentFilesState(std::sync::Mutex::new(recent)));
+
             // Create and set application menu
             let app_menu = menu::create_menu(app.handle())?;
             app.set_menu(app_menu)?;
@@ -23,11 +45,20 @@ pub fn run() {
         .expect("error while running tauri application");
 }
 
+/// The frontmost editor window, so menu actions (new/open/save/...) act
+/// on whichever document the user is currently looking at rather than
+/// always the original `"main"` window.
+fn focused_editor_window(app: &tauri::AppHandle) -> Option<tauri::WebviewWindow> {
+    app.webview_windows()
+        .into_values()
+        .find(|window| window.is_focused().unwrap_or(false))
+        .or_else(|| app.get_webview_window("main"))
+}
+
 /// Handle menu item clicks by emitting events to the frontend
 fn handle_menu_event(app: &tauri::AppHandle, event: tauri::menu::MenuEvent) {
-    let window = match app.get_webview_window("main") {
-        Some(w) => w,
-        None => return,
+    let Some(window) = focused_editor_window(app) else {
+        return;
     };
 
     match event.id().as_ref() {
@@ -47,6 +78,24 @@ fn handle_menu_event(app: &tauri::AppHandle, event: tauri::menu::MenuEvent) {
         "file:exit" => {
             let _ = window.emit("menu:file:exit", ());
         }
+        "file:recent:clear" => {
+            recent_files::clear(app);
+        }
+        id if id.starts_with("file:recent:") => {
+            let index = id["file:recent:".len()..].parse::<usize>().ok();
+            let path = index.and_then(|index| {
+                app.state::<RecentFilesState>()
+                    .0
+                    .lock()
+                    .unwrap()
+                    .paths()
+                    .get(index)
+                    .cloned()
+            });
+            if let Some(path) = path {
+                let _ = window.emit("menu:file:open-recent", path);
+            }
+        }
 
         // Edit menu
         "edit:find" => {
@@ -55,12 +104,15 @@ fn handle_menu_event(app: &tauri::AppHandle, event: tauri::menu::MenuEvent) {
 
         // View menu
         "view:edit-mode" => {
+            let _ = menu::set_active_view_mode(app, "view:edit-mode");
             let _ = window.emit("menu:view:edit-mode", ());
         }
         "view:preview-mode" => {
+            let _ = menu::set_active_view_mode(app, "view:preview-mode");
             let _ = window.emit("menu:view:preview-mode", ());
         }
         "view:split-mode" => {
+            let _ = menu::set_active_view_mode(app, "view:split-mode");
             let _ = window.emit("menu:view:split-mode", ());
         }
         "view:zoom-in" => {
@@ -1,5 +1,18 @@
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem, Submenu};
-use tauri::AppHandle;
+use tauri::menu::{
+    AboutMetadata, CheckMenuItem, Menu, MenuItem, MenuItemBuilder, MenuItemKind,
+    PredefinedMenuItem, Submenu,
+};
+use tauri::{AppHandle, Manager};
+
+use crate::recent_files::RecentFilesState;
+
+const RECENT_MENU_ID: &str = "file:recent";
+const RECENT_ITEM_PREFIX: &str = "file:recent:";
+const RECENT_CLEAR_ID: &str = "file:recent:clear";
+
+/// View-mode menu item ids, in the order they appear in the View menu.
+/// They behave as a radio group: exactly one is checked at all times.
+const VIEW_MODE_IDS: &[&str] = &["view:edit-mode", "view:preview-mode", "view:split-mode"];
 
 /// Create the application menu
 pub fn create_menu<R: tauri::Runtime>(
@@ -48,6 +61,12 @@ fn create_file_menu<R: tauri::Runtime>(
     let open_file = MenuItem::with_id(app, "file:open", "打开...", true, Some("CmdOrCtrl+O"));
     submenu.append(&open_file?)?;
 
+    // Open Recent
+    let recent_menu = Submenu::with_id(app, RECENT_MENU_ID, "最近打开", true)?;
+    let recent_paths = app.state::<RecentFilesState>().0.lock().unwrap().paths().to_vec();
+    populate_recent_submenu(app, &recent_menu, &recent_paths)?;
+    submenu.append(&recent_menu)?;
+
     submenu.append(&PredefinedMenuItem::separator(app)?)?;
 
     // Save
@@ -70,6 +89,108 @@ fn create_file_menu<R: tauri::Runtime>(
     Ok(submenu)
 }
 
+/// (Re)build the contents of the "Open Recent" submenu from `paths`,
+/// clearing whatever children it currently has.
+fn populate_recent_submenu<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    recent_menu: &Submenu<R>,
+    paths: &[String],
+) -> Result<(), Box<dyn std::error::Error>> {
+    for item in recent_menu.items()? {
+        recent_menu.remove(&item)?;
+    }
+
+    if paths.is_empty() {
+        let empty = MenuItem::with_id(app, format!("{RECENT_ITEM_PREFIX}empty"), "(无)", false, None::<&str>)?;
+        recent_menu.append(&empty)?;
+        return Ok(());
+    }
+
+    for (index, path) in paths.iter().enumerate() {
+        let basename = std::path::Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.clone());
+        let item = MenuItemBuilder::with_id(format!("{RECENT_ITEM_PREFIX}{index}"), basename)
+            .tooltip(path)
+            .build(app)?;
+        recent_menu.append(&item)?;
+    }
+
+    recent_menu.append(&PredefinedMenuItem::separator(app)?)?;
+    let clear = MenuItem::with_id(app, RECENT_CLEAR_ID, "清除最近打开记录", true, None::<&str>);
+    recent_menu.append(&clear?)?;
+
+    Ok(())
+}
+
+/// Refresh the "Open Recent" submenu from the current [`RecentFilesState`].
+/// Called whenever a file is opened/saved or the list is cleared.
+pub fn rebuild_recent_menu<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(menu) = app.menu() else {
+        return Ok(());
+    };
+
+    for item in menu.items()? {
+        let MenuItemKind::Submenu(file_menu) = item else {
+            continue;
+        };
+        if file_menu.id().as_ref() != "file" {
+            continue;
+        }
+        for child in file_menu.items()? {
+            if let MenuItemKind::Submenu(recent_menu) = child {
+                if recent_menu.id().as_ref() == RECENT_MENU_ID {
+                    let paths = app.state::<RecentFilesState>().0.lock().unwrap().paths().to_vec();
+                    populate_recent_submenu(app, &recent_menu, &paths)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check `mode_id` in the View menu's radio group and uncheck the rest,
+/// so the menu stays in sync however the mode was switched (menu click
+/// or frontend-driven `set_active_view_mode`).
+///
+/// `app.menu()` is a single menu shared by every `WebviewWindow` (the
+/// same app-wide menu bar model `create_app_menu` already assumes on
+/// macOS), so view mode is necessarily an application-wide setting here,
+/// not per-window/per-document: switching focus between two editor
+/// windows opened via `open_in_new_window` does not re-sync the
+/// checkmarks to whichever mode that window's document is in.
+pub fn set_active_view_mode<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    mode_id: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(menu) = app.menu() else {
+        return Ok(());
+    };
+
+    for item in menu.items()? {
+        let MenuItemKind::Submenu(view_menu) = item else {
+            continue;
+        };
+        if view_menu.id().as_ref() != "view" {
+            continue;
+        }
+        for child in view_menu.items()? {
+            if let MenuItemKind::Check(check_item) = child {
+                let id = check_item.id().as_ref().to_string();
+                if VIEW_MODE_IDS.contains(&id.as_str()) {
+                    check_item.set_checked(id == mode_id)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Create Edit submenu
 fn create_edit_menu<R: tauri::Runtime>(
     app: &AppHandle<R>,
@@ -117,32 +238,35 @@ fn create_view_menu<R: tauri::Runtime>(
 ) -> Result<Submenu<R>, Box<dyn std::error::Error>> {
     let submenu = Submenu::with_id(app, "view", "视图", true)?;
 
-    // Edit Mode
-    let edit_mode = MenuItem::with_id(
+    // Edit Mode (checked by default)
+    let edit_mode = CheckMenuItem::with_id(
         app,
         "view:edit-mode",
         "编辑模式",
         true,
+        true,
         Some("CmdOrCtrl+Shift+E"),
     );
     submenu.append(&edit_mode?)?;
 
     // Preview Mode
-    let preview_mode = MenuItem::with_id(
+    let preview_mode = CheckMenuItem::with_id(
         app,
         "view:preview-mode",
         "预览模式",
         true,
+        false,
         Some("CmdOrCtrl+Shift+P"),
     );
     submenu.append(&preview_mode?)?;
 
     // Split Mode
-    let split_mode = MenuItem::with_id(
+    let split_mode = CheckMenuItem::with_id(
         app,
         "view:split-mode",
         "分屏模式",
         true,
+        false,
         Some("CmdOrCtrl+Shift+L"),
     );
     submenu.append(&split_mode?)?;
@@ -170,17 +294,47 @@ fn create_view_menu<R: tauri::Runtime>(
     Ok(submenu)
 }
 
+/// Build the `AboutMetadata` shown in the native About dialog. Name and
+/// version come from the app's package info, so they stay in sync with
+/// `tauri.conf.json`; authors come from the crate's `CARGO_PKG_AUTHORS`;
+/// license, website and copyright are fixed strings.
+fn about_metadata<R: tauri::Runtime>(app: &AppHandle<R>) -> AboutMetadata {
+    let info = app.package_info();
+    let authors = env!("CARGO_PKG_AUTHORS")
+        .split(':')
+        .map(str::to_string)
+        .collect();
+    AboutMetadata {
+        name: Some(info.name.clone()),
+        version: Some(info.version.to_string()),
+        authors: Some(authors),
+        license: Some("MIT".to_string()),
+        website: Some("https://github.com/hubo1989/Medit".to_string()),
+        copyright: Some("Copyright © hubo1989".to_string()),
+        icon: app.default_window_icon().cloned(),
+        ..Default::default()
+    }
+}
+
 /// Create Help submenu
 fn create_help_menu<R: tauri::Runtime>(
     app: &AppHandle<R>,
 ) -> Result<Submenu<R>, Box<dyn std::error::Error>> {
     let submenu = Submenu::with_id(app, "help", "帮助", true)?;
 
-    // About (only on non-macOS platforms, macOS has it in App menu)
-    #[cfg(not(target_os = "macos"))]
+    // About (only on non-macOS platforms, macOS has it in App menu).
+    // Uses the OS-native About panel/dialog; the old event-based item is
+    // kept only as a fallback on platforms muda doesn't support it on.
+    #[cfg(not(any(target_os = "macos", target_os = "ios", target_os = "android")))]
+    {
+        let about = PredefinedMenuItem::about(app, Some("关于 Medit"), Some(about_metadata(app)))?;
+        submenu.append(&about)?;
+    }
+
+    #[cfg(any(target_os = "ios", target_os = "android"))]
     {
-        let about = MenuItem::with_id(app, "help:about", "关于 Medit", true, None::<&str>);
-        submenu.append(&about?)?;
+        let about_fallback = MenuItem::with_id(app, "help:about", "关于 Medit", true, None::<&str>);
+        submenu.append(&about_fallback?)?;
     }
 
     // Documentation
@@ -210,9 +364,9 @@ fn create_app_menu<R: tauri::Runtime>(
 ) -> Result<Submenu<R>, Box<dyn std::error::Error>> {
     let submenu = Submenu::with_id(app, "app", "Medit", true)?;
 
-    // About Medit
-    let about = MenuItem::with_id(app, "app:about", "关于 Medit", true, None::<&str>);
-    submenu.append(&about?)?;
+    // About Medit (native About panel)
+    let about = PredefinedMenuItem::about(app, None, Some(about_metadata(app)))?;
+    submenu.append(&about)?;
 
     submenu.append(&PredefinedMenuItem::separator(app)?)?;
 
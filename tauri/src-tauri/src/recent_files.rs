@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, Runtime};
+
+/// Maximum number of entries kept in the recent-files list.
+const MAX_RECENT_FILES: usize = 10;
+const RECENT_FILES_FILE: &str = "recent_files.json";
+
+/// Most-recently-opened file paths, newest first, persisted as JSON
+/// in the app config directory.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RecentFiles(Vec<String>);
+
+/// Managed state wrapping [`RecentFiles`] behind a mutex.
+pub struct RecentFilesState(pub Mutex<RecentFiles>);
+
+impl RecentFiles {
+    pub fn paths(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Move `path` to the front of the list, de-duplicating and
+    /// truncating to [`MAX_RECENT_FILES`].
+    pub fn push(&mut self, path: String) {
+        self.0.retain(|p| p != &path);
+        self.0.insert(0, path);
+        self.0.truncate(MAX_RECENT_FILES);
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+fn recent_files_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(RECENT_FILES_FILE))
+}
+
+/// Load the persisted recent-files list, defaulting to empty if it is
+/// missing or unreadable.
+pub fn load<R: Runtime>(app: &AppHandle<R>) -> RecentFiles {
+    recent_files_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save<R: Runtime>(app: &AppHandle<R>, recent: &RecentFiles) -> Result<(), String> {
+    let path = recent_files_path(app)?;
+    let json = serde_json::to_string_pretty(recent).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Record a newly opened/saved file, persist the list, and refresh the
+/// "Open Recent" submenu.
+pub fn record<R: Runtime>(app: &AppHandle<R>, path: String) {
+    let state = app.state::<RecentFilesState>();
+    {
+        let mut recent = state.0.lock().unwrap();
+        recent.push(path);
+        let _ = save(app, &recent);
+    }
+    let _ = crate::menu::rebuild_recent_menu(app);
+}
+
+/// Clear the recent-files list, persist it, and refresh the menu.
+pub fn clear<R: Runtime>(app: &AppHandle<R>) {
+    let state = app.state::<RecentFilesState>();
+    {
+        let mut recent = state.0.lock().unwrap();
+        recent.clear();
+        let _ = save(app, &recent);
+    }
+    let _ = crate::menu::rebuild_recent_menu(app);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_adds_new_paths_to_the_front() {
+        let mut recent = RecentFiles::default();
+        recent.push("a.md".to_string());
+        recent.push("b.md".to_string());
+        assert_eq!(recent.paths(), ["b.md", "a.md"]);
+    }
+
+    #[test]
+    fn push_deduplicates_by_moving_existing_path_to_the_front() {
+        let mut recent = RecentFiles::default();
+        recent.push("a.md".to_string());
+        recent.push("b.md".to_string());
+        recent.push("a.md".to_string());
+        assert_eq!(recent.paths(), ["a.md", "b.md"]);
+    }
+
+    #[test]
+    fn push_truncates_to_max_recent_files() {
+        let mut recent = RecentFiles::default();
+        for i in 0..(MAX_RECENT_FILES + 5) {
+            recent.push(format!("file-{i}.md"));
+        }
+        assert_eq!(recent.paths().len(), MAX_RECENT_FILES);
+        assert_eq!(recent.paths()[0], format!("file-{}.md", MAX_RECENT_FILES + 4));
+    }
+
+    #[test]
+    fn clear_empties_the_list() {
+        let mut recent = RecentFiles::default();
+        recent.push("a.md".to_string());
+        recent.clear();
+        assert!(recent.paths().is_empty());
+    }
+}